@@ -0,0 +1,251 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
+use tokio::process::Command;
+
+use crate::GoalsFile;
+
+/// Where a session's captured output and manifest live.
+pub struct SessionCapture {
+    pub dir: PathBuf,
+}
+
+/// A goal's status before and after a session, recorded in the manifest.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StatusChange {
+    pub goal_id: String,
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SessionManifest {
+    pub session_num: usize,
+    pub goal_ids: Vec<String>,
+    pub started_at_unix: u64,
+    pub ended_at_unix: u64,
+    pub exit_code: Option<i32>,
+    pub goal_status_diff: Vec<StatusChange>,
+}
+
+pub fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Create `.autogoals/artifacts/session-<n>/` for a new session.
+pub async fn start_capture(project_path: &Path, session_num: usize) -> Result<SessionCapture> {
+    let dir = project_path
+        .join(".autogoals")
+        .join("artifacts")
+        .join(format!("session-{session_num}"));
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .with_context(|| format!("Failed to create artifact directory {}", dir.display()))?;
+    Ok(SessionCapture { dir })
+}
+
+/// Spawn `command`, teeing its stdout/stderr to `stdout.log`/`stderr.log`
+/// under the capture directory (written incrementally, line by line) while
+/// still echoing to the terminal. When `reporter` is set, stdout lines that
+/// look like a step name or a goal status change are also surfaced as
+/// `Note` progress events.
+pub async fn run_captured(
+    command: &mut Command,
+    capture: &SessionCapture,
+    reporter: Option<crate::progress::ProgressReporter>,
+) -> Result<ExitStatus> {
+    let mut child = command
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to spawn 'claude' command. Is Claude Code installed?")?;
+
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let stderr = child.stderr.take().expect("stderr was piped");
+
+    let stdout_file = File::create(capture.dir.join("stdout.log"))
+        .await
+        .context("Failed to create stdout.log")?;
+    let stderr_file = File::create(capture.dir.join("stderr.log"))
+        .await
+        .context("Failed to create stderr.log")?;
+
+    let stdout_task = tokio::spawn(tee(stdout, stdout_file, false, reporter));
+    let stderr_task = tokio::spawn(tee(stderr, stderr_file, true, None));
+
+    let status = child
+        .wait()
+        .await
+        .context("Failed to wait for Claude Code process")?;
+
+    stdout_task.await.context("stdout tee task panicked")??;
+    stderr_task.await.context("stderr tee task panicked")??;
+
+    Ok(status)
+}
+
+async fn tee<R>(
+    reader: R,
+    mut file: File,
+    is_stderr: bool,
+    reporter: Option<crate::progress::ProgressReporter>,
+) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+{
+    let json_mode = reporter.map(|r| r.is_json()).unwrap_or(false);
+
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await.context("Failed to read session output")? {
+        // In JSON mode stdout is a structured NDJSON feed, so raw Claude
+        // output (still captured to the log file below) isn't echoed there.
+        if is_stderr {
+            eprintln!("{line}");
+        } else if !json_mode {
+            println!("{line}");
+        }
+
+        if let Some(reporter) = &reporter {
+            if let Some(message) = crate::progress::detect_note(&line) {
+                reporter.note(None, message);
+            }
+        }
+
+        file.write_all(line.as_bytes()).await?;
+        file.write_all(b"\n").await?;
+    }
+    file.flush().await?;
+    Ok(())
+}
+
+/// Diff goal statuses before and after a session for the manifest.
+pub fn diff_statuses(
+    before: &std::collections::HashMap<String, String>,
+    after: &GoalsFile,
+) -> Vec<StatusChange> {
+    after
+        .goals
+        .iter()
+        .filter_map(|goal| {
+            let from = before.get(&goal.id)?;
+            if *from != goal.status {
+                Some(StatusChange {
+                    goal_id: goal.id.clone(),
+                    from: from.clone(),
+                    to: goal.status.clone(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+pub async fn write_manifest(capture: &SessionCapture, manifest: &SessionManifest) -> Result<()> {
+    let content = serde_json::to_string_pretty(manifest).context("Failed to serialize session.json")?;
+    tokio::fs::write(capture.dir.join("session.json"), content)
+        .await
+        .context("Failed to write session.json")?;
+    Ok(())
+}
+
+/// Read back a past session's manifest and captured output for the `logs`
+/// subcommand.
+pub async fn read_session(project_path: &Path, session_num: usize) -> Result<(SessionManifest, String, String)> {
+    let dir = project_path
+        .join(".autogoals")
+        .join("artifacts")
+        .join(format!("session-{session_num}"));
+
+    let manifest_raw = tokio::fs::read_to_string(dir.join("session.json"))
+        .await
+        .with_context(|| format!("No manifest found for session {session_num} in {}", dir.display()))?;
+    let manifest: SessionManifest =
+        serde_json::from_str(&manifest_raw).context("Failed to parse session.json")?;
+
+    let stdout = tokio::fs::read_to_string(dir.join("stdout.log"))
+        .await
+        .unwrap_or_default();
+    let stderr = tokio::fs::read_to_string(dir.join("stderr.log"))
+        .await
+        .unwrap_or_default();
+
+    Ok((manifest, stdout, stderr))
+}
+
+/// Find the highest session number that has an artifact directory.
+pub async fn latest_session_num(project_path: &Path) -> Result<Option<usize>> {
+    let artifacts_dir = project_path.join(".autogoals").join("artifacts");
+    let mut entries = match tokio::fs::read_dir(&artifacts_dir).await {
+        Ok(entries) => entries,
+        Err(_) => return Ok(None),
+    };
+
+    let mut latest = None;
+    while let Some(entry) = entries.next_entry().await? {
+        if let Some(num) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix("session-"))
+            .and_then(|num| num.parse::<usize>().ok())
+        {
+            latest = Some(latest.map_or(num, |l: usize| l.max(num)));
+        }
+    }
+
+    Ok(latest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Goal;
+
+    fn goal(id: &str, status: &str) -> Goal {
+        Goal {
+            id: id.to_string(),
+            description: String::new(),
+            status: status.to_string(),
+            plan: None,
+            verify: None,
+        }
+    }
+
+    #[test]
+    fn diff_statuses_reports_only_changed_goals() {
+        let before: std::collections::HashMap<String, String> = [
+            ("a".to_string(), "pending".to_string()),
+            ("b".to_string(), "pending".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        let after = GoalsFile {
+            goals: vec![goal("a", "completed"), goal("b", "pending")],
+        };
+
+        let diff = diff_statuses(&before, &after);
+
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].goal_id, "a");
+        assert_eq!(diff[0].from, "pending");
+        assert_eq!(diff[0].to, "completed");
+    }
+
+    #[test]
+    fn diff_statuses_ignores_goals_not_tracked_before() {
+        let before = std::collections::HashMap::new();
+        let after = GoalsFile {
+            goals: vec![goal("a", "completed")],
+        };
+
+        assert!(diff_statuses(&before, &after).is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::artifacts::StatusChange;
+
+/// Path to the durable event log, relative to the project root.
+pub fn db_path(project_path: &Path) -> PathBuf {
+    project_path.join(".autogoals").join("state.db")
+}
+
+#[derive(Debug, Clone)]
+pub struct Transition {
+    pub from_status: String,
+    pub to_status: String,
+    pub timestamp_unix: u64,
+    pub session_num: usize,
+}
+
+fn with_connection<T, F>(path: &Path, f: F) -> Result<T>
+where
+    F: FnOnce(&Connection) -> rusqlite::Result<T>,
+{
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create .autogoals directory")?;
+    }
+    let conn = Connection::open(path).context("Failed to open state.db")?;
+    // Several worker tasks can open this file concurrently; without these,
+    // a writer colliding with another connection raises SQLITE_BUSY instead
+    // of waiting, which would otherwise propagate as a hard error and kill
+    // that worker.
+    conn.busy_timeout(Duration::from_secs(5))
+        .context("Failed to set state.db busy_timeout")?;
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .context("Failed to enable state.db WAL journal mode")?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS runs (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            session_num INTEGER NOT NULL,
+            started_at_unix INTEGER NOT NULL,
+            ended_at_unix INTEGER,
+            exit_code INTEGER
+        );
+        CREATE TABLE IF NOT EXISTS transitions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            goal_id TEXT NOT NULL,
+            from_status TEXT NOT NULL,
+            to_status TEXT NOT NULL,
+            timestamp_unix INTEGER NOT NULL,
+            session_num INTEGER NOT NULL
+        );",
+    )
+    .context("Failed to initialize state.db schema")?;
+
+    f(&conn).context("state.db query failed")
+}
+
+/// Record that a session started, returning its run id.
+pub async fn record_run_start(path: PathBuf, session_num: usize, started_at_unix: u64) -> Result<i64> {
+    tokio::task::spawn_blocking(move || {
+        with_connection(&path, |conn| {
+            conn.execute(
+                "INSERT INTO runs (session_num, started_at_unix) VALUES (?1, ?2)",
+                (session_num, started_at_unix as i64),
+            )?;
+            Ok(conn.last_insert_rowid())
+        })
+    })
+    .await
+    .context("record_run_start task panicked")?
+}
+
+/// Record that a session finished.
+pub async fn record_run_end(
+    path: PathBuf,
+    run_id: i64,
+    ended_at_unix: u64,
+    exit_code: Option<i32>,
+) -> Result<()> {
+    tokio::task::spawn_blocking(move || {
+        with_connection(&path, |conn| {
+            conn.execute(
+                "UPDATE runs SET ended_at_unix = ?1, exit_code = ?2 WHERE id = ?3",
+                (ended_at_unix as i64, exit_code, run_id),
+            )?;
+            Ok(())
+        })
+    })
+    .await
+    .context("record_run_end task panicked")?
+}
+
+/// Record every goal status transition produced by a session.
+pub async fn record_transitions(
+    path: PathBuf,
+    changes: Vec<StatusChange>,
+    session_num: usize,
+    timestamp_unix: u64,
+) -> Result<()> {
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    tokio::task::spawn_blocking(move || {
+        with_connection(&path, |conn| {
+            for change in &changes {
+                conn.execute(
+                    "INSERT INTO transitions (goal_id, from_status, to_status, timestamp_unix, session_num)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    (
+                        &change.goal_id,
+                        &change.from,
+                        &change.to,
+                        timestamp_unix as i64,
+                        session_num,
+                    ),
+                )?;
+            }
+            Ok(())
+        })
+    })
+    .await
+    .context("record_transitions task panicked")?
+}
+
+/// All goal ids that have at least one recorded transition, in first-seen order.
+pub async fn known_goal_ids(path: PathBuf) -> Result<Vec<String>> {
+    tokio::task::spawn_blocking(move || {
+        with_connection(&path, |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT goal_id FROM transitions GROUP BY goal_id ORDER BY MIN(id) ASC",
+            )?;
+            let ids = stmt
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            Ok(ids)
+        })
+    })
+    .await
+    .context("known_goal_ids task panicked")?
+}
+
+/// The full transition history for one goal, oldest first.
+pub async fn goal_timeline(path: PathBuf, goal_id: String) -> Result<Vec<Transition>> {
+    tokio::task::spawn_blocking(move || {
+        with_connection(&path, |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT from_status, to_status, timestamp_unix, session_num
+                 FROM transitions WHERE goal_id = ?1 ORDER BY id ASC",
+            )?;
+            let rows = stmt
+                .query_map((&goal_id,), |row| {
+                    Ok(Transition {
+                        from_status: row.get(0)?,
+                        to_status: row.get(1)?,
+                        timestamp_unix: row.get::<_, i64>(2)? as u64,
+                        session_num: row.get::<_, i64>(3)? as usize,
+                    })
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            Ok(rows)
+        })
+    })
+    .await
+    .context("goal_timeline task panicked")?
+}
+
+/// A goal "flip-flopped" if it revisited a status it had already left,
+/// usually a sign the agent is stuck oscillating rather than progressing.
+pub fn has_flip_flopped(timeline: &[Transition]) -> bool {
+    let mut seen = std::collections::HashSet::new();
+    for transition in timeline {
+        if !seen.insert(transition.to_status.clone()) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(from: &str, to: &str, session_num: usize, timestamp_unix: u64) -> Transition {
+        Transition {
+            from_status: from.to_string(),
+            to_status: to.to_string(),
+            timestamp_unix,
+            session_num,
+        }
+    }
+
+    #[test]
+    fn has_flip_flopped_detects_revisited_status() {
+        let timeline = vec![
+            transition("pending", "in_progress", 1, 100),
+            transition("in_progress", "completed", 2, 200),
+            transition("completed", "in_progress", 3, 300),
+        ];
+        assert!(timeline.windows(2).all(|w| w[0].timestamp_unix < w[1].timestamp_unix));
+        assert!(has_flip_flopped(&timeline));
+    }
+
+    #[test]
+    fn has_flip_flopped_false_for_linear_progress() {
+        let timeline = vec![
+            transition("pending", "in_progress", 1, 100),
+            transition("in_progress", "completed", 2, 200),
+        ];
+        assert!(!has_flip_flopped(&timeline));
+    }
+
+    #[test]
+    fn has_flip_flopped_empty_timeline() {
+        assert!(!has_flip_flopped(&[]));
+    }
+}
@@ -0,0 +1,149 @@
+use anyhow::{Context, Result};
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::protocol::{JobResult, RequestedJob};
+use crate::{depends_on_incomplete, parse_goals, write_goals, GoalsFile};
+
+struct DriverState {
+    goals_file_path: PathBuf,
+    repo_url: String,
+    token: String,
+    goals: Mutex<GoalsFile>,
+}
+
+/// Run as the driver: hold goals.yaml as the single source of truth and hand
+/// out independent, not-yet-claimed goals to whichever runner asks first.
+/// Every request must carry `Authorization: Bearer <token>` matching `token`,
+/// otherwise any host on the network could steal jobs or forge results for
+/// a protocol explicitly modeled on a build farm's runner/driver split.
+pub async fn run_driver(
+    goals_file_path: PathBuf,
+    repo_url: String,
+    bind_addr: SocketAddr,
+    token: String,
+) -> Result<()> {
+    let goals = parse_goals(&goals_file_path).context("Failed to parse goals.yaml")?;
+
+    let state = Arc::new(DriverState {
+        goals_file_path,
+        repo_url,
+        token,
+        goals: Mutex::new(goals),
+    });
+
+    let app = Router::new()
+        .route("/jobs/next", get(next_job))
+        .route("/jobs/result", post(submit_result))
+        .with_state(state);
+
+    println!("📡 Driver listening on {bind_addr}");
+    let listener = tokio::net::TcpListener::bind(bind_addr)
+        .await
+        .context("Failed to bind driver address")?;
+    axum::serve(listener, app)
+        .await
+        .context("Driver server failed")?;
+
+    Ok(())
+}
+
+/// Constant-time byte comparison so a timing side channel can't be used to
+/// recover the shared token one byte at a time.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+fn check_auth(state: &DriverState, headers: &HeaderMap) -> Result<(), StatusCode> {
+    let expected = format!("Bearer {}", state.token);
+    match headers.get(axum::http::header::AUTHORIZATION) {
+        Some(value) if constant_time_eq(value.as_bytes(), expected.as_bytes()) => Ok(()),
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+async fn next_job(
+    State(state): State<Arc<DriverState>>,
+    headers: HeaderMap,
+) -> Result<Json<Option<RequestedJob>>, StatusCode> {
+    check_auth(&state, &headers)?;
+
+    let mut goals = state.goals.lock().await;
+
+    // Hand out the first goal that's ready to run and isn't blocked on a
+    // dependency declared in its `plan` - same check the local `--jobs N`
+    // path applies, so the driver can't dispatch a goal out of order.
+    let candidate_id = goals
+        .goals
+        .iter()
+        .find(|g| {
+            matches!(g.status.as_str(), "pending" | "ready_for_execution")
+                && !depends_on_incomplete(g, &goals)
+        })
+        .map(|g| g.id.clone());
+
+    let job = candidate_id.and_then(|id| {
+        goals.goals.iter_mut().find(|g| g.id == id).map(|goal| {
+            goal.status = "in_progress".to_string();
+            RequestedJob {
+                goal_id: goal.id.clone(),
+                description: goal.description.clone(),
+                plan: goal.plan.clone(),
+                repo_url: state.repo_url.clone(),
+                commit: "HEAD".to_string(),
+            }
+        })
+    });
+
+    // Persist the in_progress claim immediately: goals.yaml is the stated
+    // single source of truth, so it shouldn't lag the in-memory state for
+    // as long as a job is outstanding.
+    if job.is_some() {
+        if let Err(err) = write_goals(&state.goals_file_path, &goals) {
+            eprintln!("⚠️  Failed to persist goals.yaml: {err:#}");
+        }
+    }
+
+    Ok(Json(job))
+}
+
+async fn submit_result(
+    State(state): State<Arc<DriverState>>,
+    headers: HeaderMap,
+    Json(result): Json<JobResult>,
+) -> Result<(), StatusCode> {
+    check_auth(&state, &headers)?;
+
+    let mut goals = state.goals.lock().await;
+
+    if let Some(goal) = goals.goals.iter_mut().find(|g| g.id == result.goal_id) {
+        goal.status = if result.passed {
+            "completed".to_string()
+        } else {
+            "pending".to_string()
+        };
+        println!(
+            "📬 Runner reported '{}': {}",
+            result.goal_id,
+            if result.passed { "passed" } else { "failed" }
+        );
+        if let Some(message) = result.message {
+            println!("   {message}");
+        }
+    }
+
+    if let Err(err) = write_goals(&state.goals_file_path, &goals) {
+        eprintln!("⚠️  Failed to persist goals.yaml: {err:#}");
+    }
+
+    Ok(())
+}
@@ -1,10 +1,34 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use std::process::Stdio;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use tokio::process::Command;
+use tokio::sync::Mutex as AsyncMutex;
+
+mod artifacts;
+mod dbctx;
+mod driver;
+mod notifier;
+mod progress;
+mod protocol;
+mod runner;
+mod verify;
+mod workers;
+use notifier::{NotifierConfig, NotifyEvent};
+use progress::ProgressReporter;
+use verify::VerifySpec;
+use workers::WorkerPool;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
 
 #[derive(Parser)]
 #[command(name = "autogoals")]
@@ -21,6 +45,86 @@ enum Commands {
         /// Path to project directory (defaults to current directory)
         #[arg(default_value = ".")]
         path: PathBuf,
+        /// Max number of independent goals to run concurrently
+        #[arg(long, default_value_t = 1)]
+        jobs: usize,
+        /// Emit a newline-delimited JSON progress feed instead of the
+        /// human-readable output
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Show the current status of every goal (and, while a run is in
+    /// progress, every worker)
+    Status {
+        /// Path to project directory (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// Run as a driver: hold goals.yaml and farm goals out to runners
+    Driver {
+        /// Path to project directory (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Address to bind the driver's HTTP endpoint on
+        #[arg(long, default_value = "0.0.0.0:4411")]
+        bind: std::net::SocketAddr,
+        /// Git URL runners should clone to pick up a job
+        #[arg(long)]
+        repo_url: String,
+        /// Shared secret runners must present as a bearer token; also read
+        /// from AUTOGOALS_DRIVER_TOKEN
+        #[arg(long, env = "AUTOGOALS_DRIVER_TOKEN")]
+        token: String,
+    },
+    /// Run as a runner: long-poll a driver for jobs and execute them
+    Runner {
+        /// Driver HTTP address, e.g. http://driver-host:4411
+        #[arg(long)]
+        host: String,
+        /// Directory to check out jobs into
+        #[arg(long, default_value = ".autogoals/runner-workdir")]
+        workdir: PathBuf,
+        /// Shared secret to present to the driver as a bearer token; also
+        /// read from AUTOGOALS_DRIVER_TOKEN
+        #[arg(long, env = "AUTOGOALS_DRIVER_TOKEN")]
+        token: String,
+    },
+    /// Pause the worker currently running a goal under `start --jobs N`
+    Pause {
+        /// Path to project directory (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Goal id to pause
+        goal: String,
+    },
+    /// Cancel the worker currently running a goal under `start --jobs N`
+    Cancel {
+        /// Path to project directory (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Goal id to cancel
+        goal: String,
+    },
+    /// Replay or tail a past session's captured output
+    Logs {
+        /// Path to project directory (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Session number to show (defaults to the most recent one)
+        #[arg(long)]
+        session: Option<usize>,
+        /// Keep following the session's logs as they're written
+        #[arg(long)]
+        follow: bool,
+    },
+    /// Print per-goal status timelines from the durable event log
+    History {
+        /// Path to project directory (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Only show the timeline for this goal id
+        #[arg(long)]
+        goal: Option<String>,
     },
 }
 
@@ -29,13 +133,17 @@ struct GoalsFile {
     goals: Vec<Goal>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 struct Goal {
     id: String,
     description: String,
     status: String,
     #[serde(default)]
     plan: Option<String>,
+    /// Optional independent check run after a session claims this goal is
+    /// done. See the `verify` module for the script API.
+    #[serde(default)]
+    verify: Option<VerifySpec>,
 }
 
 impl GoalsFile {
@@ -72,16 +180,37 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Start { path } => start(path).await?,
+        Commands::Start { path, jobs, format } => start(path, jobs, format == OutputFormat::Json).await?,
+        Commands::Status { path } => show_status(path).await?,
+        Commands::Driver {
+            path,
+            bind,
+            repo_url,
+            token,
+        } => driver::run_driver(path.join("goals.yaml"), repo_url, bind, token).await?,
+        Commands::Runner { host, workdir, token } => runner::run_runner(host, workdir, token).await?,
+        Commands::Pause { path, goal } => send_worker_control(path, goal, "pause").await?,
+        Commands::Cancel { path, goal } => send_worker_control(path, goal, "cancel").await?,
+        Commands::Logs {
+            path,
+            session,
+            follow,
+        } => show_logs(path, session, follow).await?,
+        Commands::History { path, goal } => show_history(path, goal).await?,
     }
 
     Ok(())
 }
 
-async fn start(project_path: PathBuf) -> Result<()> {
-    println!("🚀 AutoGoals Runner - Phase 2");
-    println!("📁 Project: {}", project_path.display());
-    println!();
+async fn start(project_path: PathBuf, jobs: usize, json: bool) -> Result<()> {
+    let reporter = ProgressReporter::new(json);
+    let notifiers = Arc::new(notifier::load(&project_path).await?);
+
+    if !json {
+        println!("🚀 AutoGoals Runner - Phase 2");
+        println!("📁 Project: {}", project_path.display());
+        println!();
+    }
 
     // Verify project path exists
     if !project_path.exists() {
@@ -97,7 +226,13 @@ async fn start(project_path: PathBuf) -> Result<()> {
         );
     }
 
-    println!("✓ Found goals.yaml");
+    if !json {
+        println!("✓ Found goals.yaml");
+    }
+
+    if jobs > 1 {
+        return start_concurrent(project_path, goals_file_path, jobs, reporter, notifiers).await;
+    }
 
     // Session loop - continue until all goals complete
     let mut session_num = 1;
@@ -105,62 +240,679 @@ async fn start(project_path: PathBuf) -> Result<()> {
     loop {
         // Parse goals.yaml to check current state
         let goals = parse_goals(&goals_file_path).context("Failed to parse goals.yaml")?;
+        // Only the goals that were actually pending/in-flight when this
+        // session started belong to it; a goal that was already completed
+        // isn't part of what this session worked on.
+        let statuses_before: HashMap<String, String> = goals
+            .goals
+            .iter()
+            .filter(|g| {
+                matches!(
+                    g.status.as_str(),
+                    "pending" | "ready_for_execution" | "in_progress" | "ready_for_verification"
+                )
+            })
+            .map(|g| (g.id.clone(), g.status.clone()))
+            .collect();
 
         let (completed, in_progress, pending) = goals.count_by_status();
         let total = goals.goals.len();
 
-        println!();
-        println!("📊 Goal Status: {completed}/{total} completed, {in_progress} in progress, {pending} pending");
+        if !json {
+            println!();
+            println!("📊 Goal Status: {completed}/{total} completed, {in_progress} in progress, {pending} pending");
+        }
 
         // Check if there's work to do
         if !goals.has_pending_work() {
-            println!();
-            println!("🎉 All goals completed!");
+            if !json {
+                println!();
+                println!("🎉 All goals completed!");
+            }
+            reporter.finished(completed, in_progress, pending);
+            let handles = notifier::dispatch(
+                &notifiers,
+                NotifyEvent::RunFinished {
+                    completed,
+                    in_progress,
+                    pending,
+                },
+            );
+            notifier::join(handles).await;
             break;
         }
 
         // Spawn Claude Code session
-        println!();
-        println!("🤖 Starting Claude Code session #{session_num}...");
-        println!();
+        if !json {
+            println!();
+            println!("🤖 Starting Claude Code session #{session_num}...");
+            println!();
+        }
 
-        let mut child = Command::new("claude")
-            .current_dir(&project_path)
-            .stdin(Stdio::inherit())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .spawn()
-            .context("Failed to spawn 'claude' command. Is Claude Code installed?")?;
+        let capture = artifacts::start_capture(&project_path, session_num).await?;
+        let started_at_unix = artifacts::now_unix();
+        let db_path = dbctx::db_path(&project_path);
+        let run_id = dbctx::record_run_start(db_path.clone(), session_num, started_at_unix).await?;
 
-        // Wait for session to complete
-        let status = child
-            .wait()
-            .await
-            .context("Failed to wait for Claude Code process")?;
+        let mut command = Command::new("claude");
+        command.current_dir(&project_path);
+        let status = artifacts::run_captured(&mut command, &capture, Some(reporter)).await?;
 
-        println!();
+        if !json {
+            println!();
+        }
         if status.success() {
-            println!("✅ Session #{session_num} completed");
+            if !json {
+                println!("✅ Session #{session_num} completed");
+            }
         } else {
-            println!(
-                "⚠️  Session #{session_num} exited with code: {}",
+            let message = format!(
+                "Session #{session_num} exited with code: {}",
                 status.code().unwrap_or(-1)
             );
+            if !json {
+                println!("⚠️  {message}");
+            } else {
+                reporter.failed(message);
+            }
+            notifier::dispatch(
+                &notifiers,
+                NotifyEvent::SessionFailed {
+                    session_num,
+                    exit_code: status.code(),
+                },
+            );
         }
 
+        // Independently verify any goal the session just claimed as completed,
+        // rather than trusting the self-reported status.
+        verify_newly_completed_goals(&goals_file_path, &project_path, &statuses_before, &notifiers, reporter).await?;
+
+        let after = parse_goals(&goals_file_path).context("Failed to parse goals.yaml")?;
+        let diff = artifacts::diff_statuses(&statuses_before, &after);
+        let ended_at_unix = artifacts::now_unix();
+
+        for change in &diff {
+            reporter.note(None, format!("{}: {} -> {}", change.goal_id, change.from, change.to));
+        }
+
+        let manifest = artifacts::SessionManifest {
+            session_num,
+            goal_ids: statuses_before.keys().cloned().collect(),
+            started_at_unix,
+            ended_at_unix,
+            exit_code: status.code(),
+            goal_status_diff: diff.clone(),
+        };
+        artifacts::write_manifest(&capture, &manifest).await?;
+
+        dbctx::record_transitions(db_path.clone(), diff, session_num, ended_at_unix).await?;
+        dbctx::record_run_end(db_path, run_id, ended_at_unix, status.code()).await?;
+
         session_num += 1;
 
         // Re-check goals.yaml to see if we should continue
-        println!("🔄 Checking for remaining work...");
+        if !json {
+            println!("🔄 Checking for remaining work...");
+        }
+    }
+
+    if !json {
+        println!();
+        println!("✨ All goals completed successfully!");
+    }
+    Ok(())
+}
+
+/// A `--jobs N` variant of `start`'s session loop: goals that are ready and
+/// don't depend on another not-yet-completed goal (via a mention of its id
+/// in `plan`) are handed to a worker pool and run concurrently, up to
+/// `jobs` Claude sessions at a time. Dependent goals simply wait their turn.
+async fn start_concurrent(
+    project_path: PathBuf,
+    goals_file_path: PathBuf,
+    jobs: usize,
+    reporter: ProgressReporter,
+    notifiers: Arc<NotifierConfig>,
+) -> Result<()> {
+    if !reporter.is_json() {
+        println!("🧵 Running with up to {jobs} concurrent workers");
+    }
+
+    let file_lock = Arc::new(AsyncMutex::new(()));
+    let session_counter = Arc::new(AtomicUsize::new(1));
+    let mut pool = WorkerPool::new();
+    let workers_state_path = project_path.join(".autogoals").join("workers.json");
+    let control_dir = project_path.join(".autogoals").join("control");
+
+    loop {
+        pool.reap_finished();
+        pool.apply_pending_controls(&control_dir).await?;
+
+        let goals = parse_goals(&goals_file_path).context("Failed to parse goals.yaml")?;
+        if !goals.has_pending_work() && pool.is_empty() {
+            let (completed, in_progress, pending) = goals.count_by_status();
+            if !reporter.is_json() {
+                println!();
+                println!("🎉 All goals completed!");
+            }
+            reporter.finished(completed, in_progress, pending);
+            let handles = notifier::dispatch(
+                &notifiers,
+                NotifyEvent::RunFinished {
+                    completed,
+                    in_progress,
+                    pending,
+                },
+            );
+            notifier::join(handles).await;
+            break;
+        }
+
+        let running = pool.running_goal_ids();
+        let slots = jobs.saturating_sub(pool.len());
+        // `in_progress` goals with no worker currently running them are
+        // orphans left behind by an interrupted prior run (Ctrl-C, crash) -
+        // without resuming them here, `has_pending_work()` would stay true
+        // forever while `ready` never admits them, hanging the loop.
+        let ready: Vec<Goal> = goals
+            .goals
+            .iter()
+            .filter(|g| matches!(g.status.as_str(), "pending" | "ready_for_execution" | "in_progress"))
+            .filter(|g| !running.contains(&g.id))
+            .filter(|g| !depends_on_incomplete(g, &goals))
+            .take(slots)
+            .cloned()
+            .collect();
+
+        for goal in ready {
+            if goal.status == "in_progress" && !reporter.is_json() {
+                println!(
+                    "♻️  Goal '{}' was left in_progress with no worker running it, resuming",
+                    goal.id
+                );
+            }
+            let session_num = session_counter.fetch_add(1, Ordering::SeqCst);
+            if !reporter.is_json() {
+                println!(
+                    "🤖 Starting Claude Code session #{session_num} for goal '{}'...",
+                    goal.id
+                );
+            }
+            let session = run_goal_session(
+                project_path.clone(),
+                goals_file_path.clone(),
+                Arc::clone(&file_lock),
+                goal.clone(),
+                session_num,
+                reporter,
+                Arc::clone(&notifiers),
+            );
+            pool.spawn(goal.id, session);
+        }
+
+        if !pool.is_empty() {
+            pool.write_status_file(&workers_state_path).await?;
+        }
+
+        if !pool.is_empty() && !reporter.is_json() {
+            print!("\r🧵 workers: ");
+            for worker in pool.status().await {
+                print!(
+                    "[{} {} {}s] ",
+                    worker.goal_id,
+                    worker.state.as_str(),
+                    worker.started_at.elapsed().as_secs()
+                );
+            }
+            std::io::stdout().flush().ok();
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    }
+
+    WorkerPool::clear_status_file(&workers_state_path).await?;
+
+    if !reporter.is_json() {
+        println!();
+        println!("✨ All goals completed successfully!");
+    }
+    Ok(())
+}
+
+/// Run a single Claude Code session scoped to `goal`, then verify and
+/// persist the outcome under `file_lock` so concurrent workers don't race
+/// on goals.yaml.
+async fn run_goal_session(
+    project_path: PathBuf,
+    goals_file_path: PathBuf,
+    file_lock: Arc<AsyncMutex<()>>,
+    goal: Goal,
+    session_num: usize,
+    reporter: ProgressReporter,
+    notifiers: Arc<NotifierConfig>,
+) -> Result<()> {
+    let capture = artifacts::start_capture(&project_path, session_num).await?;
+    let started_at_unix = artifacts::now_unix();
+    let db_path = dbctx::db_path(&project_path);
+    let run_id = dbctx::record_run_start(db_path.clone(), session_num, started_at_unix).await?;
+
+    let mut command = Command::new("claude");
+    command.current_dir(&project_path).env("AUTOGOALS_GOAL_ID", &goal.id);
+    let status = artifacts::run_captured(&mut command, &capture, Some(reporter)).await?;
+
+    if status.success() {
+        if !reporter.is_json() {
+            println!("✅ Session for goal '{}' completed", goal.id);
+        }
+    } else {
+        let message = format!(
+            "Session for goal '{}' exited with code: {}",
+            goal.id,
+            status.code().unwrap_or(-1)
+        );
+        if !reporter.is_json() {
+            println!("⚠️  {message}");
+        } else {
+            reporter.failed(message);
+        }
+        notifier::dispatch(
+            &notifiers,
+            NotifyEvent::SessionFailed {
+                session_num,
+                exit_code: status.code(),
+            },
+        );
     }
 
+    let _guard = file_lock.lock().await;
+    let mut goals = parse_goals(&goals_file_path).context("Failed to parse goals.yaml")?;
+
+    if let Some(current) = goals.goals.iter_mut().find(|g| g.id == goal.id) {
+        if current.status == "completed" && goal.status != "completed" {
+            let outcome = verify::verify_goal(current, &project_path, reporter)
+                .await
+                .with_context(|| format!("Failed to run verification for goal '{}'", current.id))?;
+
+            if outcome.passed {
+                if !reporter.is_json() {
+                    println!("✓ Goal '{}' verified", current.id);
+                }
+                notifier::dispatch(
+                    &notifiers,
+                    NotifyEvent::GoalCompleted {
+                        goal_id: current.id.clone(),
+                    },
+                );
+            } else {
+                if !reporter.is_json() {
+                    println!(
+                        "✗ Goal '{}' failed verification, reverting to in_progress: {}",
+                        current.id,
+                        outcome.message.as_deref().unwrap_or("no details")
+                    );
+                }
+                current.status = "in_progress".to_string();
+                write_goals(&goals_file_path, &goals)?;
+            }
+        }
+    }
+
+    let final_status = goals
+        .goals
+        .iter()
+        .find(|g| g.id == goal.id)
+        .map(|g| g.status.clone())
+        .unwrap_or(goal.status.clone());
+
+    let ended_at_unix = artifacts::now_unix();
+    let diff = if final_status != goal.status {
+        vec![artifacts::StatusChange {
+            goal_id: goal.id.clone(),
+            from: goal.status.clone(),
+            to: final_status,
+        }]
+    } else {
+        Vec::new()
+    };
+
+    for change in &diff {
+        reporter.note(None, format!("{}: {} -> {}", change.goal_id, change.from, change.to));
+    }
+
+    let manifest = artifacts::SessionManifest {
+        session_num,
+        goal_ids: vec![goal.id.clone()],
+        started_at_unix,
+        ended_at_unix,
+        exit_code: status.code(),
+        goal_status_diff: diff.clone(),
+    };
+    artifacts::write_manifest(&capture, &manifest).await?;
+
+    dbctx::record_transitions(db_path.clone(), diff, session_num, ended_at_unix).await?;
+    dbctx::record_run_end(db_path, run_id, ended_at_unix, status.code()).await?;
+
+    Ok(())
+}
+
+/// A goal depends on another goal if its `plan` text mentions that goal's
+/// id and that goal isn't completed yet. The id is matched as a whole
+/// "word" (bounded by non-alphanumeric characters) so e.g. a plan
+/// mentioning `setup-db` doesn't also match the unrelated goal `setup`.
+fn depends_on_incomplete(goal: &Goal, goals: &GoalsFile) -> bool {
+    let Some(plan) = &goal.plan else {
+        return false;
+    };
+
+    let words: Vec<&str> = plan
+        .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '-')
+        .collect();
+
+    goals
+        .goals
+        .iter()
+        .any(|other| other.id != goal.id && other.status != "completed" && words.contains(&other.id.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn goal(id: &str, status: &str, plan: Option<&str>) -> Goal {
+        Goal {
+            id: id.to_string(),
+            description: String::new(),
+            status: status.to_string(),
+            plan: plan.map(str::to_string),
+            verify: None,
+        }
+    }
+
+    #[test]
+    fn depends_on_incomplete_does_not_match_substring_ids() {
+        // "setup" is a substring of "setup-db" but an unrelated goal; only
+        // the exact id mentioned in the plan should count as a dependency.
+        let goals = GoalsFile {
+            goals: vec![
+                goal("a", "pending", Some("depends on setup-db")),
+                goal("setup", "pending", None),
+                goal("setup-db", "pending", None),
+            ],
+        };
+
+        assert!(depends_on_incomplete(&goals.goals[0], &goals));
+
+        // Proven by removing the real dependency: with only the unrelated
+        // "setup" goal incomplete, "a" should no longer be blocked.
+        let goals_without_setup_db = GoalsFile {
+            goals: vec![
+                goal("a", "pending", Some("depends on setup-db")),
+                goal("setup", "pending", None),
+                goal("setup-db", "completed", None),
+            ],
+        };
+        assert!(!depends_on_incomplete(&goals_without_setup_db.goals[0], &goals_without_setup_db));
+    }
+
+    #[test]
+    fn depends_on_incomplete_does_not_match_numeric_suffix_ids() {
+        // "goal-1" is a prefix of "goal-10"; a plan naming "goal-10"
+        // shouldn't falsely depend on the unrelated "goal-1".
+        let goals = GoalsFile {
+            goals: vec![
+                goal("a", "pending", Some("depends on goal-10")),
+                goal("goal-1", "pending", None),
+                goal("goal-10", "completed", None),
+            ],
+        };
+
+        assert!(!depends_on_incomplete(&goals.goals[0], &goals));
+    }
+
+    #[test]
+    fn depends_on_incomplete_ignores_completed_dependency() {
+        let goals = GoalsFile {
+            goals: vec![
+                goal("a", "pending", Some("depends on setup")),
+                goal("setup", "completed", None),
+            ],
+        };
+
+        assert!(!depends_on_incomplete(&goals.goals[0], &goals));
+    }
+}
+
+/// A worker entry as persisted to `.autogoals/workers.json` by `start --jobs
+/// N`; mirrors `workers::WorkerPool::write_status_file`'s output.
+#[derive(Debug, Deserialize)]
+struct PersistedWorkerStatus {
+    goal_id: String,
+    state: String,
+    elapsed_secs: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct PersistedWorkerPool {
+    updated_at_unix: u64,
+    workers: Vec<PersistedWorkerStatus>,
+}
+
+/// Worker state older than this is assumed to be left over from a crashed
+/// or otherwise no-longer-running `start --jobs N` invocation.
+const WORKER_STATE_STALE_SECS: u64 = 5;
+
+/// Queue a `pause`/`cancel` request for the worker running `goal` by
+/// dropping a request file under `.autogoals/control/`, the same mailbox a
+/// running `start --jobs N` polls on its loop. Only takes effect while a
+/// `start --jobs N` process is actually running for this project.
+async fn send_worker_control(project_path: PathBuf, goal: String, command: &'static str) -> Result<()> {
+    let control_dir = project_path.join(".autogoals").join("control");
+    tokio::fs::create_dir_all(&control_dir)
+        .await
+        .context("Failed to create .autogoals/control directory")?;
+
+    let request = workers::PersistedControlRequest {
+        goal_id: goal.clone(),
+        command: command.to_string(),
+    };
+    let content = serde_json::to_string(&request).context("Failed to serialize control request")?;
+    tokio::fs::write(control_dir.join(format!("{goal}.json")), content)
+        .await
+        .context("Failed to write control request")?;
+
+    println!("📨 Queued '{command}' for goal '{goal}' (applies on the next poll of a running `start --jobs N`)");
+    Ok(())
+}
+
+async fn show_status(project_path: PathBuf) -> Result<()> {
+    let goals_file_path = project_path.join("goals.yaml");
+    let goals = parse_goals(&goals_file_path).context("Failed to parse goals.yaml")?;
+
+    println!("📋 Goal status for {}", project_path.display());
+    println!();
+    for goal in &goals.goals {
+        println!("  [{:<24}] {:<24} {}", goal.id, goal.status, goal.description);
+    }
+
+    let (completed, in_progress, pending) = goals.count_by_status();
+    println!();
+    println!(
+        "📊 {completed}/{} completed, {in_progress} in progress, {pending} pending",
+        goals.goals.len()
+    );
+
+    let workers_state_path = project_path.join(".autogoals").join("workers.json");
+    if let Ok(content) = fs::read_to_string(&workers_state_path) {
+        match serde_json::from_str::<PersistedWorkerPool>(&content) {
+            Ok(snapshot) => {
+                let age = artifacts::now_unix().saturating_sub(snapshot.updated_at_unix);
+                println!();
+                if age > WORKER_STATE_STALE_SECS {
+                    println!(
+                        "🧵 Workers (stale, last updated {age}s ago — is a `start --jobs N` run still active?):"
+                    );
+                } else {
+                    println!("🧵 Workers:");
+                }
+                for worker in &snapshot.workers {
+                    println!(
+                        "  [{:<24}] {:<8} {}s",
+                        worker.goal_id, worker.state, worker.elapsed_secs
+                    );
+                }
+            }
+            Err(err) => {
+                eprintln!("⚠️  Failed to parse {}: {err:#}", workers_state_path.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn show_logs(project_path: PathBuf, session: Option<usize>, follow: bool) -> Result<()> {
+    let session_num = match session {
+        Some(n) => n,
+        None => artifacts::latest_session_num(&project_path)
+            .await?
+            .context("No captured sessions found in .autogoals/artifacts")?,
+    };
+
+    let (manifest, stdout, stderr) = artifacts::read_session(&project_path, session_num).await?;
+
+    println!("📼 Session #{session_num}");
+    println!("   goals: {}", manifest.goal_ids.join(", "));
+    println!("   exit code: {:?}", manifest.exit_code);
+    if !manifest.goal_status_diff.is_empty() {
+        println!("   status changes:");
+        for change in &manifest.goal_status_diff {
+            println!("     {} : {} -> {}", change.goal_id, change.from, change.to);
+        }
+    }
     println!();
-    println!("✨ All goals completed successfully!");
+    print!("{stdout}");
+    eprint!("{stderr}");
+
+    if follow {
+        let dir = project_path
+            .join(".autogoals")
+            .join("artifacts")
+            .join(format!("session-{session_num}"));
+        let mut offset = stdout.len() as u64;
+
+        loop {
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            let content = fs::read_to_string(dir.join("stdout.log")).unwrap_or_default();
+            if (content.len() as u64) > offset {
+                print!("{}", &content[offset as usize..]);
+                offset = content.len() as u64;
+            }
+        }
+    }
+
     Ok(())
 }
 
-fn parse_goals(path: &PathBuf) -> Result<GoalsFile> {
+async fn show_history(project_path: PathBuf, goal: Option<String>) -> Result<()> {
+    let db_path = dbctx::db_path(&project_path);
+
+    let goal_ids = match goal {
+        Some(id) => vec![id],
+        None => dbctx::known_goal_ids(db_path.clone()).await?,
+    };
+
+    if goal_ids.is_empty() {
+        println!("No run history recorded yet in {}", db_path.display());
+        return Ok(());
+    }
+
+    for goal_id in goal_ids {
+        let timeline = dbctx::goal_timeline(db_path.clone(), goal_id.clone()).await?;
+
+        println!("🗂  {goal_id}");
+        for transition in &timeline {
+            println!(
+                "   session #{}: {} -> {} (at {})",
+                transition.session_num, transition.from_status, transition.to_status, transition.timestamp_unix
+            );
+        }
+
+        if dbctx::has_flip_flopped(&timeline) {
+            println!("   ⚠️  this goal flip-flopped between statuses — it may be stuck");
+        }
+
+        println!();
+    }
+
+    Ok(())
+}
+
+fn parse_goals(path: &Path) -> Result<GoalsFile> {
     let content = fs::read_to_string(path).context("Failed to read goals.yaml")?;
     let goals: GoalsFile = serde_yaml::from_str(&content).context("Failed to parse YAML")?;
     Ok(goals)
 }
+
+fn write_goals(path: &Path, goals: &GoalsFile) -> Result<()> {
+    let content = serde_yaml::to_string(goals).context("Failed to serialize goals.yaml")?;
+    fs::write(path, content).context("Failed to write goals.yaml")?;
+    Ok(())
+}
+
+/// For every goal that flipped to `completed` during the session that just
+/// ran, run its verification script. Goals that fail verification are pushed
+/// back to `in_progress` so the loop picks them up again instead of trusting
+/// the self-reported status.
+async fn verify_newly_completed_goals(
+    goals_file_path: &Path,
+    project_path: &Path,
+    statuses_before: &HashMap<String, String>,
+    notifiers: &NotifierConfig,
+    reporter: ProgressReporter,
+) -> Result<()> {
+    let mut goals = parse_goals(goals_file_path).context("Failed to parse goals.yaml")?;
+    let mut changed = false;
+
+    for goal in &mut goals.goals {
+        let was_completed = statuses_before
+            .get(&goal.id)
+            .map(|s| s == "completed")
+            .unwrap_or(false);
+
+        if goal.status == "completed" && !was_completed {
+            reporter.note(None, format!("🔬 verifying goal '{}'...", goal.id));
+
+            let outcome = verify::verify_goal(goal, project_path, reporter)
+                .await
+                .with_context(|| format!("Failed to run verification for goal '{}'", goal.id))?;
+
+            if outcome.passed {
+                reporter.note(None, format!("✓ goal '{}' verified", goal.id));
+                notifier::dispatch(
+                    notifiers,
+                    NotifyEvent::GoalCompleted {
+                        goal_id: goal.id.clone(),
+                    },
+                );
+            } else {
+                reporter.note(
+                    None,
+                    format!(
+                        "✗ goal '{}' failed verification, reverting to in_progress: {}",
+                        goal.id,
+                        outcome.message.as_deref().unwrap_or("no details")
+                    ),
+                );
+                goal.status = "in_progress".to_string();
+                changed = true;
+            }
+        }
+    }
+
+    if changed {
+        write_goals(goals_file_path, &goals)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Loaded from `.autogoals/notifiers.yaml`; empty (no notifiers configured)
+/// if that file doesn't exist.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NotifierConfig {
+    #[serde(default)]
+    pub notifiers: Vec<Notifier>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Notifier {
+    /// POST the event as JSON to `url`.
+    Webhook { url: String },
+    /// Run `command` through the shell, with the event JSON in the
+    /// `AUTOGOALS_EVENT` environment variable.
+    Shell { command: String },
+}
+
+/// A key run or goal event, serialized and handed to each configured
+/// notifier.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum NotifyEvent {
+    GoalCompleted {
+        goal_id: String,
+    },
+    SessionFailed {
+        session_num: usize,
+        exit_code: Option<i32>,
+    },
+    RunFinished {
+        completed: usize,
+        in_progress: usize,
+        pending: usize,
+    },
+}
+
+/// Load `.autogoals/notifiers.yaml`, or an empty config if it isn't there.
+pub async fn load(project_path: &Path) -> Result<NotifierConfig> {
+    let path = project_path.join(".autogoals").join("notifiers.yaml");
+    if !path.exists() {
+        return Ok(NotifierConfig::default());
+    }
+
+    let content = tokio::fs::read_to_string(&path)
+        .await
+        .context("Failed to read notifiers.yaml")?;
+    serde_yaml::from_str(&content).context("Failed to parse notifiers.yaml")
+}
+
+/// Fire `event` to every configured notifier. Each notifier runs on its own
+/// spawned task so a slow or failing webhook never blocks the session loop.
+/// Returns the tasks' handles so a caller about to exit (e.g. after the
+/// final `RunFinished`) can wait for them instead of racing the process
+/// exit against an in-flight notification.
+pub fn dispatch(config: &NotifierConfig, event: NotifyEvent) -> Vec<tokio::task::JoinHandle<()>> {
+    config
+        .notifiers
+        .clone()
+        .into_iter()
+        .map(|notifier| {
+            let event = event.clone();
+            tokio::spawn(async move {
+                if let Err(err) = fire(&notifier, &event).await {
+                    eprintln!("⚠️  Notifier failed: {err:#}");
+                }
+            })
+        })
+        .collect()
+}
+
+/// Wait for previously `dispatch`ed notifications to finish, up to a short
+/// timeout each, so a long-running unattended user's ping isn't silently
+/// dropped by the process exiting before the spawned task runs.
+pub async fn join(handles: Vec<tokio::task::JoinHandle<()>>) {
+    for handle in handles {
+        let _ = tokio::time::timeout(Duration::from_secs(10), handle).await;
+    }
+}
+
+async fn fire(notifier: &Notifier, event: &NotifyEvent) -> Result<()> {
+    match notifier {
+        Notifier::Webhook { url } => {
+            reqwest::Client::new()
+                .post(url)
+                .json(event)
+                .send()
+                .await
+                .context("Failed to POST webhook notifier")?
+                .error_for_status()
+                .context("Webhook notifier returned an error status")?;
+            Ok(())
+        }
+        Notifier::Shell { command } => {
+            let payload = serde_json::to_string(event).context("Failed to serialize event")?;
+            let status = Command::new("sh")
+                .arg("-c")
+                .arg(command)
+                .env("AUTOGOALS_EVENT", payload)
+                .status()
+                .await
+                .context("Failed to run shell notifier")?;
+            anyhow::ensure!(
+                status.success(),
+                "shell notifier exited with code {:?}",
+                status.code()
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+/// A single point-in-time event in a run, emitted either as a
+/// newline-delimited JSON object (`--format json`) or as the equivalent
+/// human-readable line.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProgressEvent {
+    Note {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        percentage: Option<u8>,
+        message: String,
+    },
+    Finished {
+        completed: usize,
+        in_progress: usize,
+        pending: usize,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+/// Emits `ProgressEvent`s for a run, either as NDJSON to stdout or as the
+/// existing human-readable prints.
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressReporter {
+    json: bool,
+}
+
+impl ProgressReporter {
+    pub fn new(json: bool) -> Self {
+        Self { json }
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.json
+    }
+
+    pub fn note(&self, percentage: Option<u8>, message: impl Into<String>) {
+        self.emit(ProgressEvent::Note {
+            percentage,
+            message: message.into(),
+        });
+    }
+
+    pub fn finished(&self, completed: usize, in_progress: usize, pending: usize) {
+        self.emit(ProgressEvent::Finished {
+            completed,
+            in_progress,
+            pending,
+        });
+    }
+
+    pub fn failed(&self, error: impl Into<String>) {
+        self.emit(ProgressEvent::Failed {
+            error: error.into(),
+        });
+    }
+
+    fn emit(&self, event: ProgressEvent) {
+        if self.json {
+            match serde_json::to_string(&event) {
+                Ok(line) => println!("{line}"),
+                Err(err) => eprintln!("⚠️  Failed to serialize progress event: {err}"),
+            }
+            return;
+        }
+
+        match event {
+            ProgressEvent::Note { percentage, message } => match percentage {
+                Some(p) => println!("📝 [{p:>3}%] {message}"),
+                None => println!("📝 {message}"),
+            },
+            ProgressEvent::Finished {
+                completed,
+                in_progress,
+                pending,
+            } => {
+                println!("✨ {completed} completed, {in_progress} in progress, {pending} pending");
+            }
+            ProgressEvent::Failed { error } => println!("⚠️  {error}"),
+        }
+    }
+}
+
+/// Best-effort parse of a line of Claude's own output into a `Note`
+/// message: step names (`Step: ...`) and goal status changes
+/// (`<goal-id>: <from> -> <to>`) are surfaced; everything else is left
+/// alone so the feed doesn't flood with every printed line.
+pub fn detect_note(line: &str) -> Option<String> {
+    let trimmed = line.trim();
+
+    if let Some(step) = trimmed.strip_prefix("Step:") {
+        return Some(format!("step: {}", step.trim()));
+    }
+
+    if trimmed.contains(" -> ") && trimmed.contains(':') {
+        return Some(trimmed.to_string());
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_note_parses_step_lines() {
+        assert_eq!(
+            detect_note("Step: running tests"),
+            Some("step: running tests".to_string())
+        );
+    }
+
+    #[test]
+    fn detect_note_parses_goal_status_changes() {
+        assert_eq!(
+            detect_note("goal-1: pending -> in_progress"),
+            Some("goal-1: pending -> in_progress".to_string())
+        );
+    }
+
+    #[test]
+    fn detect_note_ignores_unrelated_lines() {
+        assert_eq!(detect_note("just some ordinary Claude output"), None);
+    }
+}
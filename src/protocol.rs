@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// A job handed from a driver to a runner: enough to check out the project
+/// and resume work on a single goal.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RequestedJob {
+    pub goal_id: String,
+    pub description: String,
+    #[serde(default)]
+    pub plan: Option<String>,
+    pub repo_url: String,
+    pub commit: String,
+}
+
+/// What a runner reports back once it has driven a Claude session for a
+/// `RequestedJob` to completion (or failure).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct JobResult {
+    pub goal_id: String,
+    pub passed: bool,
+    /// Unified diff of the changes the session produced, if any.
+    #[serde(default)]
+    pub diff: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
@@ -0,0 +1,164 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+
+use crate::protocol::{JobResult, RequestedJob};
+
+/// Client side of the runner/driver protocol: long-polls a driver for work
+/// and reports results back.
+pub struct RunnerClient {
+    host: String,
+    token: String,
+    http: reqwest::Client,
+}
+
+impl RunnerClient {
+    pub fn new(host: String, token: String) -> Self {
+        Self {
+            host,
+            token,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Ask the driver for the next available job, if any.
+    async fn request_job(&self) -> Result<Option<RequestedJob>> {
+        let response = self
+            .http
+            .get(format!("{}/jobs/next", self.host))
+            .bearer_auth(&self.token)
+            .send()
+            .await
+            .context("Failed to reach driver")?
+            .error_for_status()
+            .context("Driver returned an error")?;
+
+        let job: Option<RequestedJob> = response
+            .json()
+            .await
+            .context("Failed to parse driver response")?;
+        Ok(job)
+    }
+
+    async fn submit_result(&self, result: &JobResult) -> Result<()> {
+        self.http
+            .post(format!("{}/jobs/result", self.host))
+            .bearer_auth(&self.token)
+            .json(result)
+            .send()
+            .await
+            .context("Failed to submit job result to driver")?
+            .error_for_status()
+            .context("Driver rejected job result")?;
+        Ok(())
+    }
+}
+
+/// Run as a runner: repeatedly long-poll the driver, check out and work a
+/// job when one is available, then report the outcome.
+pub async fn run_runner(host: String, workdir: PathBuf, token: String) -> Result<()> {
+    let client = RunnerClient::new(host, token);
+
+    println!("🏃 Runner connecting to {}", client.host);
+
+    loop {
+        let job = match client.request_job().await? {
+            Some(job) => job,
+            None => {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                continue;
+            }
+        };
+
+        println!("📦 Picked up job '{}': {}", job.goal_id, job.description);
+        let result = work_job(&job, &workdir).await.unwrap_or_else(|err| JobResult {
+            goal_id: job.goal_id.clone(),
+            passed: false,
+            diff: None,
+            message: Some(format!("{err:#}")),
+        });
+
+        client.submit_result(&result).await?;
+    }
+}
+
+async fn work_job(job: &RequestedJob, workdir: &Path) -> Result<JobResult> {
+    let project_path = workdir.join(&job.goal_id);
+
+    if project_path.join(".git").exists() {
+        // A previous attempt at this goal left a checkout behind (e.g. the
+        // driver requeued it after a failure) - reuse it instead of letting
+        // `git clone` fail on an existing, non-empty directory.
+        let fetch_status = Command::new("git")
+            .args(["fetch", "origin"])
+            .current_dir(&project_path)
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .await
+            .context("Failed to spawn git fetch")?;
+        anyhow::ensure!(fetch_status.success(), "git fetch failed for {}", job.repo_url);
+    } else {
+        tokio::fs::create_dir_all(workdir)
+            .await
+            .context("Failed to create runner workdir")?;
+
+        let clone_status = Command::new("git")
+            .args(["clone", &job.repo_url, &project_path.to_string_lossy()])
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .await
+            .context("Failed to spawn git clone")?;
+        anyhow::ensure!(clone_status.success(), "git clone failed for {}", job.repo_url);
+    }
+
+    let checkout_status = Command::new("git")
+        .args(["checkout", &job.commit])
+        .current_dir(&project_path)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .await
+        .context("Failed to spawn git checkout")?;
+    anyhow::ensure!(checkout_status.success(), "git checkout {} failed", job.commit);
+
+    // Discard any local changes left behind by a previous failed attempt at
+    // this goal before resuming work.
+    let reset_status = Command::new("git")
+        .args(["reset", "--hard", &job.commit])
+        .current_dir(&project_path)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .await
+        .context("Failed to spawn git reset")?;
+    anyhow::ensure!(reset_status.success(), "git reset --hard {} failed", job.commit);
+
+    let session_status = Command::new("claude")
+        .current_dir(&project_path)
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context("Failed to spawn 'claude' command. Is Claude Code installed?")?
+        .wait()
+        .await
+        .context("Failed to wait for Claude Code process")?;
+
+    let diff_output = Command::new("git")
+        .args(["diff"])
+        .current_dir(&project_path)
+        .output()
+        .await
+        .context("Failed to capture diff")?;
+
+    Ok(JobResult {
+        goal_id: job.goal_id.clone(),
+        passed: session_status.success(),
+        diff: Some(String::from_utf8_lossy(&diff_output.stdout).to_string()),
+        message: None,
+    })
+}
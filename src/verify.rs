@@ -0,0 +1,191 @@
+use anyhow::{Context, Result};
+use mlua::{Lua, Table};
+use std::path::{Path, PathBuf};
+use std::process::Stdio as StdStdio;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::runtime::Handle;
+use tokio::time::timeout;
+
+use crate::progress::ProgressReporter;
+use crate::Goal;
+
+/// How a goal's verification is expressed in goals.yaml: either a path to a
+/// `.lua` file, or a script written inline.
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+#[serde(untagged)]
+pub enum VerifySpec {
+    Path(String),
+    Inline { script: String },
+}
+
+/// Result of running a goal's verification script.
+#[derive(Debug)]
+pub struct VerifyOutcome {
+    pub passed: bool,
+    pub message: Option<String>,
+}
+
+/// Fallback script used when a goal has no explicit `verify` field: it just
+/// runs `cargo test` and passes if that exits cleanly.
+const DEFAULT_VERIFY_SCRIPT: &str = r#"
+step("cargo test")
+local result = run({ "cargo", "test" }, {})
+if result.exit_status ~= 0 then
+    error("cargo test failed:\n" .. result.stderr)
+end
+"#;
+
+/// Run the verification script for `goal` against `project_path`, returning
+/// whether it passed. Script failures (Lua errors, nonzero step exit codes)
+/// count as a failed verification rather than propagating as hard errors, so
+/// the caller can keep the goal `in_progress` and loop again. `step()`
+/// progress inside the script is surfaced through `reporter`, same as the
+/// rest of the run loop, so `--format json` stays pure NDJSON.
+pub async fn verify_goal(goal: &Goal, project_path: &Path, reporter: ProgressReporter) -> Result<VerifyOutcome> {
+    let script = load_script(goal, project_path)
+        .await
+        .context("Failed to load verification script")?;
+    let project_path = project_path.to_path_buf();
+    let handle = Handle::current();
+
+    let outcome =
+        tokio::task::spawn_blocking(move || run_script(&script, &project_path, handle, reporter))
+            .await
+            .context("Verification task panicked")??;
+
+    Ok(outcome)
+}
+
+async fn load_script(goal: &Goal, project_path: &Path) -> Result<String> {
+    match &goal.verify {
+        Some(VerifySpec::Inline { script }) => Ok(script.clone()),
+        Some(VerifySpec::Path(path)) => {
+            let full_path = project_path.join(path);
+            tokio::fs::read_to_string(&full_path)
+                .await
+                .with_context(|| format!("Failed to read verify script: {}", full_path.display()))
+        }
+        None => Ok(DEFAULT_VERIFY_SCRIPT.to_string()),
+    }
+}
+
+fn run_script(script: &str, project_path: &Path, handle: Handle, reporter: ProgressReporter) -> Result<VerifyOutcome> {
+    let lua = Lua::new();
+    let cwd = Arc::new(Mutex::new(project_path.to_path_buf()));
+
+    {
+        let cwd = Arc::clone(&cwd);
+        let handle = handle.clone();
+        let run_fn = lua
+            .create_function(move |lua, (command, params): (Table, Table)| {
+                run_command(lua, &handle, &cwd, command, params)
+            })
+            .context("Failed to register run()")?;
+        lua.globals().set("run", run_fn)?;
+    }
+
+    let step_fn = lua
+        .create_function(move |_, name: String| {
+            reporter.note(None, format!("verify step: {name}"));
+            Ok(())
+        })
+        .context("Failed to register step()")?;
+    lua.globals().set("step", step_fn)?;
+
+    {
+        let cwd = Arc::clone(&cwd);
+        let cwd_fn = lua
+            .create_function(move |_, path: String| {
+                *cwd.lock().unwrap() = path.into();
+                Ok(())
+            })
+            .context("Failed to register cwd()")?;
+        lua.globals().set("cwd", cwd_fn)?;
+    }
+
+    match lua.load(script).exec() {
+        Ok(()) => Ok(VerifyOutcome {
+            passed: true,
+            message: None,
+        }),
+        Err(err) => Ok(VerifyOutcome {
+            passed: false,
+            message: Some(err.to_string()),
+        }),
+    }
+}
+
+/// `params` understood by a script's `run(command, params)` call:
+///   - `env`: a table of string env vars to set on the child
+///   - `timeout_secs`: kill the child and fail the step if it runs longer
+struct RunParams {
+    env: Vec<(String, String)>,
+    timeout_secs: Option<u64>,
+}
+
+fn read_params(params: &Table) -> mlua::Result<RunParams> {
+    let env = match params.get::<_, Option<Table>>("env")? {
+        Some(table) => table
+            .pairs::<String, String>()
+            .collect::<mlua::Result<Vec<_>>>()?,
+        None => Vec::new(),
+    };
+    let timeout_secs = params.get::<_, Option<u64>>("timeout_secs")?;
+    Ok(RunParams { env, timeout_secs })
+}
+
+fn run_command<'lua>(
+    lua: &'lua Lua,
+    handle: &Handle,
+    cwd: &Mutex<PathBuf>,
+    command: Table,
+    params: Table,
+) -> mlua::Result<Table<'lua>> {
+    let mut args: Vec<String> = Vec::new();
+    for entry in command.sequence_values::<String>() {
+        args.push(entry?);
+    }
+    let Some((program, rest)) = args.split_first() else {
+        return Err(mlua::Error::RuntimeError(
+            "run() requires a non-empty command table".into(),
+        ));
+    };
+
+    let params = read_params(&params)?;
+    let cwd = cwd.lock().unwrap().clone();
+    let program = program.clone();
+    let rest = rest.to_vec();
+
+    let program_name = program.clone();
+    let output = handle
+        .block_on(async move {
+            let mut child = Command::new(&program);
+            child
+                .args(&rest)
+                .current_dir(&cwd)
+                .envs(params.env)
+                .stdin(StdStdio::null())
+                // Without this, dropping `child.output()` on a timeout (below)
+                // leaves the process running in the background instead of
+                // killing it - tokio's default is to not touch a child it
+                // didn't explicitly wait on.
+                .kill_on_drop(true);
+
+            match params.timeout_secs {
+                Some(secs) => match timeout(Duration::from_secs(secs), child.output()).await {
+                    Ok(result) => result.map_err(|e| e.to_string()),
+                    Err(_) => Err(format!("{program} timed out after {secs}s")),
+                },
+                None => child.output().await.map_err(|e| e.to_string()),
+            }
+        })
+        .map_err(|e| mlua::Error::RuntimeError(format!("failed to run {program_name}: {e}")))?;
+
+    let result = lua.create_table()?;
+    result.set("exit_status", output.status.code().unwrap_or(-1))?;
+    result.set("stdout", String::from_utf8_lossy(&output.stdout).to_string())?;
+    result.set("stderr", String::from_utf8_lossy(&output.stderr).to_string())?;
+    Ok(result)
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerState {
+    Active,
+    Idle,
+    Dead,
+}
+
+impl WorkerState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkerState::Active => "active",
+            WorkerState::Idle => "idle",
+            WorkerState::Dead => "dead",
+        }
+    }
+}
+
+/// Messages a caller can send to steer an in-flight worker, delivered over
+/// `WorkerPool::send_control_for_goal`.
+#[derive(Debug, Clone, Copy)]
+pub enum WorkerControl {
+    Pause,
+    Cancel,
+}
+
+impl WorkerControl {
+    fn from_command(command: &str) -> Option<Self> {
+        match command {
+            "pause" => Some(WorkerControl::Pause),
+            "cancel" => Some(WorkerControl::Cancel),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkerStatus {
+    pub goal_id: String,
+    pub state: WorkerState,
+    pub started_at: Instant,
+    pub started_at_unix: u64,
+}
+
+/// A pause/cancel request for the worker owning `goal_id`, written by the
+/// `pause`/`cancel` CLI subcommands to `.autogoals/control/<goal_id>.json`
+/// for a running `start --jobs N` to pick up and forward over the worker's
+/// control channel.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PersistedControlRequest {
+    pub goal_id: String,
+    pub command: String,
+}
+
+/// The on-disk shape of `WorkerStatus`, written by `start --jobs N` to
+/// `.autogoals/workers.json` so a separate `autogoals status` invocation
+/// can see live worker state.
+#[derive(Debug, Serialize)]
+struct PersistedWorkerStatus {
+    goal_id: String,
+    state: &'static str,
+    started_at_unix: u64,
+    elapsed_secs: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct PersistedWorkerPool {
+    updated_at_unix: u64,
+    workers: Vec<PersistedWorkerStatus>,
+}
+
+struct Worker {
+    control_tx: mpsc::Sender<WorkerControl>,
+    status: Arc<Mutex<WorkerStatus>>,
+    handle: JoinHandle<()>,
+}
+
+/// A pool of workers, each owning one in-flight goal's Claude session. Used
+/// by `start --jobs N` to run independent goals concurrently while keeping a
+/// control channel and status view for each worker.
+#[derive(Default)]
+pub struct WorkerPool {
+    workers: HashMap<usize, Worker>,
+    next_id: usize,
+}
+
+impl WorkerPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawn a worker that drives `session` to completion for `goal_id`.
+    pub fn spawn<F>(&mut self, goal_id: String, session: F) -> usize
+    where
+        F: std::future::Future<Output = Result<()>> + Send + 'static,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let (control_tx, mut control_rx) = mpsc::channel(4);
+        let status = Arc::new(Mutex::new(WorkerStatus {
+            goal_id,
+            state: WorkerState::Active,
+            started_at: Instant::now(),
+            started_at_unix: crate::artifacts::now_unix(),
+        }));
+
+        let worker_status = Arc::clone(&status);
+        let handle = tokio::spawn(async move {
+            tokio::pin!(session);
+            loop {
+                tokio::select! {
+                    result = &mut session => {
+                        if let Err(err) = result {
+                            eprintln!("⚠️  Worker failed: {err:#}");
+                        }
+                        break;
+                    }
+                    Some(ctrl) = control_rx.recv() => {
+                        match ctrl {
+                            WorkerControl::Cancel => {
+                                println!("🛑 Worker cancelled");
+                                break;
+                            }
+                            WorkerControl::Pause => {
+                                worker_status.lock().await.state = WorkerState::Idle;
+                            }
+                        }
+                    }
+                    else => break,
+                }
+            }
+            worker_status.lock().await.state = WorkerState::Dead;
+        });
+
+        self.workers.insert(
+            id,
+            Worker {
+                control_tx,
+                status,
+                handle,
+            },
+        );
+        id
+    }
+
+    /// Send `msg` to the worker currently running `goal_id`, if any.
+    /// Returns `true` if a matching worker was found and sent to.
+    pub async fn send_control_for_goal(&self, goal_id: &str, msg: WorkerControl) -> bool {
+        for worker in self.workers.values() {
+            if worker.status.lock().await.goal_id == goal_id {
+                let _ = worker.control_tx.send(msg).await;
+                return true;
+            }
+        }
+        false
+    }
+
+    pub async fn status(&self) -> Vec<WorkerStatus> {
+        let mut statuses = Vec::with_capacity(self.workers.len());
+        for worker in self.workers.values() {
+            statuses.push(worker.status.lock().await.clone());
+        }
+        statuses
+    }
+
+    /// Persist the current worker statuses to `path` so a separate `status`
+    /// invocation can read live state across processes.
+    pub async fn write_status_file(&self, path: &Path) -> Result<()> {
+        let workers = self
+            .status()
+            .await
+            .into_iter()
+            .map(|w| PersistedWorkerStatus {
+                goal_id: w.goal_id,
+                state: w.state.as_str(),
+                started_at_unix: w.started_at_unix,
+                elapsed_secs: w.started_at.elapsed().as_secs(),
+            })
+            .collect();
+
+        let snapshot = PersistedWorkerPool {
+            updated_at_unix: crate::artifacts::now_unix(),
+            workers,
+        };
+        let content = serde_json::to_string_pretty(&snapshot).context("Failed to serialize worker status")?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .context("Failed to create .autogoals directory")?;
+        }
+        tokio::fs::write(path, content)
+            .await
+            .context("Failed to write worker status file")?;
+        Ok(())
+    }
+
+    /// Remove the persisted status file once a run has no more workers left.
+    pub async fn clear_status_file(path: &Path) -> Result<()> {
+        match tokio::fs::remove_file(path).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err).context("Failed to remove worker status file"),
+        }
+    }
+
+    /// Pick up any pending `pause`/`cancel` requests left in `control_dir` by
+    /// the `pause`/`cancel` CLI subcommands and forward them to the worker
+    /// running that goal, if one is currently running. Each request file is
+    /// removed once read, whether or not a matching worker was found.
+    pub async fn apply_pending_controls(&self, control_dir: &Path) -> Result<()> {
+        let mut entries = match tokio::fs::read_dir(control_dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err).context("Failed to read .autogoals/control directory"),
+        };
+
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .context("Failed to read .autogoals/control directory entry")?
+        {
+            let path = entry.path();
+            let content = match tokio::fs::read_to_string(&path).await {
+                Ok(content) => content,
+                Err(_) => continue,
+            };
+            if let Ok(request) = serde_json::from_str::<PersistedControlRequest>(&content) {
+                if let Some(control) = WorkerControl::from_command(&request.command) {
+                    self.send_control_for_goal(&request.goal_id, control).await;
+                }
+            }
+            tokio::fs::remove_file(&path).await.ok();
+        }
+        Ok(())
+    }
+
+    /// Drop workers whose task has already finished.
+    pub fn reap_finished(&mut self) {
+        self.workers.retain(|_, worker| !worker.handle.is_finished());
+    }
+
+    pub fn running_goal_ids(&self) -> Vec<String> {
+        // Best-effort snapshot; status is behind a lock updated from the
+        // worker task so this can lag by a poll.
+        self.workers
+            .values()
+            .filter_map(|w| w.status.try_lock().ok().map(|s| s.goal_id.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.workers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.workers.is_empty()
+    }
+}